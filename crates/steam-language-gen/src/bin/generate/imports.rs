@@ -0,0 +1,90 @@
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+const IMPORT_PREFIX: &str = "#import \"";
+
+/// Resolves the `#import "..."` graph starting at `entry_path` and returns every reachable
+/// steamd file concatenated into a single source, imports emitted before the file that imports
+/// them so forward references (e.g. shared enums) are always defined by the time they're used.
+///
+/// Panics if an `#import` cannot be read, or if the import graph contains a cycle.
+pub fn resolve(entry_path: &Path) -> String {
+    let mut visiting = Vec::new();
+    let mut loaded = HashSet::new();
+    let mut combined = String::new();
+
+    resolve_into(entry_path, &mut visiting, &mut loaded, &mut combined);
+
+    combined
+}
+
+fn resolve_into(
+    path: &Path,
+    visiting: &mut Vec<PathBuf>,
+    loaded: &mut HashSet<PathBuf>,
+    combined: &mut String,
+) {
+    let canonical = path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+
+    if visiting.contains(&canonical) {
+        panic!("import cycle detected: {}", describe_chain(visiting, &canonical));
+    }
+
+    // Already pulled in by an earlier branch of the import graph - nothing more to do.
+    if !loaded.insert(canonical.clone()) {
+        return;
+    }
+
+    let source = std::fs::read_to_string(path)
+        .unwrap_or_else(|err| panic!("failed to read steamd file {}: {}", path.display(), err));
+
+    visiting.push(canonical);
+
+    let base_dir = path.parent().unwrap_or_else(|| Path::new("."));
+    for import in extract_imports(&source) {
+        resolve_into(&base_dir.join(import), visiting, loaded, combined);
+    }
+
+    combined.push_str(&source);
+    combined.push('\n');
+
+    visiting.pop();
+}
+
+/// Extracts the quoted filenames out of every `#import "..."` line in `source`.
+fn extract_imports(source: &str) -> Vec<&str> {
+    source
+        .lines()
+        .filter_map(|line| line.trim().strip_prefix(IMPORT_PREFIX))
+        .filter_map(|rest| rest.strip_suffix('"'))
+        .collect()
+}
+
+fn describe_chain(visiting: &[PathBuf], closing: &Path) -> String {
+    let mut chain: Vec<String> = visiting.iter().map(|p| p.display().to_string()).collect();
+    chain.push(closing.display().to_string());
+    chain.join(" -> ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::extract_imports;
+
+    #[test]
+    fn test_extract_imports() {
+        let source = "#import \"steammsg_base.steamd\"\r\nclass Foo {\r\n};\r\n";
+        assert_eq!(vec!["steammsg_base.steamd"], extract_imports(source));
+    }
+
+    #[test]
+    fn test_extract_imports_multiple() {
+        let source = "#import \"a.steamd\"\n#import \"b.steamd\"\nclass Foo {\n};\n";
+        assert_eq!(vec!["a.steamd", "b.steamd"], extract_imports(source));
+    }
+
+    #[test]
+    fn test_extract_imports_none() {
+        let source = "class Foo {\r\n};\r\n";
+        assert!(extract_imports(source).is_empty());
+    }
+}
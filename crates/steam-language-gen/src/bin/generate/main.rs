@@ -0,0 +1,747 @@
+use std::collections::HashMap;
+use std::path::Path;
+
+use inflector::cases::snakecase::to_snake_case;
+use nom::bytes::complete::{is_a, tag, take_until};
+use nom::IResult;
+use petgraph::Graph;
+
+use steam_language_gen::file::generate_file_from_tree;
+
+mod imports;
+
+struct Keyword {
+    keyword: String,
+    equivalent: String,
+}
+
+/// Entry point of the `#import` graph. Every other steamd file the generator reads is pulled in
+/// transitively through [`imports::resolve`], relative to this one.
+const ENTRY_FILE: &str =
+    concat!(env!("CARGO_MANIFEST_DIR"), "/assets/SteamKit/Resources/SteamLanguage/steammsg.steamd");
+
+fn main() {
+    let file_steam_msg = imports::resolve(Path::new(ENTRY_FILE));
+    let derive_config = DeriveConfig::default();
+
+    let mut graph = Graph::<String, &str>::new();
+    let entry = graph.add_node("entry".to_string());
+    let mut next_block = file_steam_msg.as_ref();
+
+    while let Some(value) = extract_class_code(next_block) {
+        let current_class_identifier = String::from_utf8(Vec::from(value.2)).unwrap();
+
+        // node insertion
+        let class_node = graph.add_node(current_class_identifier);
+        graph.add_edge(entry, class_node, "class");
+
+        let derive_node = graph.add_node(derive_config.class_attribute());
+        graph.add_edge(class_node, derive_node, "derive");
+
+        let member = extract_attr_lines(value.0).unwrap();
+
+        let members: Vec<String> = extract_members_exhaustive(member.0)
+            .iter()
+            .map(|c| String::from_utf8(Vec::from(*c)).unwrap())
+            .collect();
+
+        let parsed_stmts = parse_stmts(members);
+        for stmt in parsed_stmts {
+            // Only `(name, type)` field pairs become "0" nodes; `const` members parse to three
+            // tokens and would break the two-at-a-time pairing the renderer relies on.
+            if stmt.len() != 2 {
+                continue;
+            }
+            stmt.iter().for_each(|c| {
+                let edge = graph.add_node(c.to_string());
+                graph.add_edge(class_node, edge, "0");
+            })
+        }
+
+        next_block = value.1;
+    }
+
+    let mut next_enum_block = file_steam_msg.as_ref();
+    while let Some(enum_block) = extract_enum_code(next_enum_block) {
+        let enum_node = graph.add_node(enum_block.name.clone());
+        let enum_edge = if enum_block.is_flags { "enum_flags" } else { "enum" };
+        graph.add_edge(entry, enum_node, enum_edge);
+
+        let members = extract_members_exhaustive(enum_block.body);
+        let parsed_members = parse_enum_members(members);
+
+        let rendered = render_enum_item(&enum_block, &parsed_members, &derive_config);
+        let code_node = graph.add_node(rendered);
+        graph.add_edge(enum_node, code_node, "code");
+
+        next_enum_block = enum_block.rest;
+    }
+
+    generate_file_from_tree(graph, entry);
+}
+
+const CLASS: &[u8] = br#"class "#;
+const CLASS_EOF: &[u8] = br#"};"#;
+const ENUM: &[u8] = br#"enum "#;
+const ENUM_FLAGS: &[u8] = br#"flags"#;
+const OBSOLETE: &str = "obsolete";
+
+/// Controls which derive macros are attached to each generated kind. Defaults to exactly what's
+/// hand-written in the confirmations module (`Confirmation`'s derives for classes,
+/// `EConfirmationType`'s for enums), so generated code doesn't need manual post-editing to match
+/// the rest of the crate.
+struct DeriveConfig {
+    class_derives: Vec<&'static str>,
+    enum_derives: Vec<&'static str>,
+    /// Whether to also emit a `FromStr`/`FromPrimitive`-backed conversion impl for enums.
+    emit_enum_from_primitive: bool,
+}
+
+impl Default for DeriveConfig {
+    fn default() -> Self {
+        Self {
+            class_derives: vec!["Debug", "Clone", "PartialEq"],
+            enum_derives: vec![
+                "Debug",
+                "Copy",
+                "Clone",
+                "Serialize",
+                "Deserialize",
+                "Eq",
+                "PartialEq",
+                "FromPrimitive",
+            ],
+            emit_enum_from_primitive: true,
+        }
+    }
+}
+
+impl DeriveConfig {
+    /// Renders the `#[derive(...)]` line attached to generated message classes.
+    fn class_attribute(&self) -> String {
+        render_derive_attribute(&self.class_derives)
+    }
+
+    /// Renders the `#[derive(...)]` line attached to generated enums.
+    fn enum_attribute(&self) -> String {
+        render_derive_attribute(&self.enum_derives)
+    }
+}
+
+fn render_derive_attribute(derives: &[&str]) -> String {
+    format!("#[derive({})]", derives.join(", "))
+}
+
+type ResultSlice<'a> = IResult<&'a [u8], &'a [u8]>;
+type U82tuple<'a> = (&'a [u8], &'a [u8]);
+type U83tuple<'a> = (&'a [u8], &'a [u8], &'a [u8]);
+
+/// Result of extracting a single `enum ... { .. };` block from the stream.
+struct EnumBlock<'a> {
+    /// Remaining stream, starting right after this enum's `};`
+    rest: &'a [u8],
+    /// Raw member lines between the enum's opening and closing brace
+    body: &'a [u8],
+    /// Enum identifier, in PascalCase as declared in the steamd source
+    name: String,
+    /// Whether the `flags` modifier followed the enum name
+    is_flags: bool,
+}
+
+/// A single parsed `Ident = <rhs>;` enum member, with its right-hand side already evaluated.
+struct EnumMember {
+    name: String,
+    value: i64,
+    obsolete: bool,
+}
+
+fn take_until_class(stream: &[u8]) -> ResultSlice {
+    take_until(CLASS)(&stream)
+}
+
+fn take_until_class_eof(stream: &[u8]) -> ResultSlice {
+    take_until(CLASS_EOF)(&stream)
+}
+
+fn take_until_enum(stream: &[u8]) -> ResultSlice {
+    take_until(ENUM)(&stream)
+}
+
+fn take_until_open_bracket(stream: &[u8]) -> ResultSlice {
+    take_until("{")(&stream)
+}
+
+fn take_until_ident(stream: &[u8]) -> ResultSlice {
+    take_until("uint")(&stream)
+}
+
+fn take_until_lessthan(stream: &[u8]) -> ResultSlice {
+    take_until("<")(&stream)
+}
+
+/// takes a class ident and returns as a node
+fn class_as_node() {}
+
+/// Returns class code, along with class name
+fn extract_class_code(stream: &[u8]) -> Option<U83tuple> {
+    let parser = nom::sequence::preceded(
+        // Ditch anything before the preamble
+        take_until_class,
+        nom::sequence::preceded(tag(CLASS), take_until_class_eof),
+    );
+
+    // swap positions so index 1 is the rest
+    parser(stream).ok().map(|c| {
+        let parsed_classname = take_until_lessthan(c.1).unwrap();
+        (c.1, c.0, parsed_classname.1)
+    })
+}
+
+/// Returns an enum block, along with its name and whether it's a `flags` enum
+fn extract_enum_code(stream: &[u8]) -> Option<EnumBlock> {
+    let parser = nom::sequence::preceded(
+        // Ditch anything before the preamble
+        take_until_enum,
+        nom::sequence::preceded(tag(ENUM), take_until_class_eof),
+    );
+
+    // c.0 is the remaining stream (starts at "};"), c.1 is the enum's header + body - same
+    // layout extract_class_code relies on.
+    parser(stream).ok().map(|c| {
+        let header = take_until_open_bracket(c.1).unwrap().1;
+        let declaration = std::str::from_utf8(header).unwrap().trim();
+        let is_flags = declaration.split(' ').any(|token| token.as_bytes() == ENUM_FLAGS);
+        let name = declaration.split(' ').next().unwrap().to_string();
+        let body = extract_attr_lines(c.1).unwrap().0;
+
+        EnumBlock { rest: c.0, body, name, is_flags }
+    })
+}
+
+fn extract_attr_lines(stream: &[u8]) -> Option<U82tuple> {
+    let preamble_parser = nom::sequence::preceded(take_until_open_bracket, tag("{"));
+    preamble_parser(stream).ok()
+}
+
+/// Returns None if there are no more available members for extraction
+fn clear_lines_tab(stream: &[u8]) -> ResultSlice {
+    is_a("\r\n\t")(stream)
+}
+
+/// Discard newlines, tabs and ';' eof
+fn extract_member(stream: &[u8]) -> Option<U82tuple> {
+    nom::sequence::preceded(clear_lines_tab, take_until(";"))(stream).ok().map(|c| {
+        //removes ; on the 1st byte
+        let x = &c.0[1..];
+        (c.1, x)
+    })
+}
+
+/// Extract attributes inside a class and returns as Vec of bytes
+fn extract_members_exhaustive(mut attributes_code: &[u8]) -> Vec<&[u8]> {
+    let mut tokens = Vec::new();
+    while let Some(value) = extract_member(attributes_code) {
+        tokens.push(value.0);
+        attributes_code = value.1;
+    }
+    tokens
+}
+
+fn split_words_to_vec(declaration: &str) -> Vec<&str> {
+    declaration.split(' ').collect()
+}
+
+/// Returns matched types
+fn match_type(slice: &str) -> &str {
+    match slice {
+        "ulong" => "u64",
+        "long" => "i64",
+        "uint" => "u32",
+        "int" => "i32",
+        "ushort" => "u16",
+        "short" => "i16",
+        "byte" => "u8",
+        value => value,
+    }
+}
+
+/// Returns Vector that has each stmt parsed into rust code by [parse_stmt]
+fn parse_stmts(stmt_vector: Vec<String>) -> Vec<Vec<String>> {
+    stmt_vector.iter().map(|stmt| parse_stmt(stmt)).collect()
+}
+
+/// Parses a single member statement, dispatching on a leading modifier token
+/// (`steamidmarshal`/`gameidmarshal`/`boolmarshal`/`proto`/`const`) when one is present, falling
+/// back to the plain `type ident` form otherwise.
+fn parse_stmt(stmt: &str) -> Vec<String> {
+    let stmt_tokens = split_words_to_vec(stmt);
+
+    match stmt_tokens[0] {
+        "steamidmarshal" => parse_marshaled_stmt(&stmt_tokens, "SteamID"),
+        "gameidmarshal" => parse_marshaled_stmt(&stmt_tokens, "GameID"),
+        "boolmarshal" => parse_marshaled_stmt(&stmt_tokens, "bool"),
+        "proto" => parse_proto_stmt(&stmt_tokens),
+        "const" => parse_const_stmt(&stmt_tokens),
+        _ => parse_plain_stmt(&stmt_tokens),
+    }
+}
+
+/// `type ident`, the common case: a bare field declaration with no modifier.
+fn parse_plain_stmt(stmt_tokens: &[&str]) -> Vec<String> {
+    let mut new_vec: Vec<String> = Vec::with_capacity(2);
+    new_vec.push(to_snake_case(stmt_tokens[1]));
+
+    if is_array(stmt_tokens[0]) {
+        new_vec.push(format!("[u8; {}]", array_extract_size(stmt_tokens[0])));
+    } else {
+        new_vec.push(match_type(stmt_tokens[0]).to_string());
+    }
+
+    new_vec
+}
+
+/// `steamidmarshal ulong ident` / `gameidmarshal ulong ident` / `boolmarshal byte ident` - the
+/// declared wire type (`stmt_tokens[1]`) only describes how the value is read off the wire; the
+/// generated field itself carries the newtype/hint named by `rust_type`.
+fn parse_marshaled_stmt(stmt_tokens: &[&str], rust_type: &str) -> Vec<String> {
+    vec![to_snake_case(stmt_tokens[2]), rust_type.to_string()]
+}
+
+/// `proto < MessageType > ident` - the field's wire type is a protobuf message, not a scalar.
+/// steamd writes this both spaced (`< MessageType >`, 3 tokens) and tight (`<MessageType>`, 1
+/// token), so the message type is recovered by concatenating everything between `proto` and the
+/// trailing ident and trimming the angle brackets, rather than assuming a fixed token position.
+fn parse_proto_stmt(stmt_tokens: &[&str]) -> Vec<String> {
+    let ident = stmt_tokens[stmt_tokens.len() - 1];
+    let bracketed = stmt_tokens[1..stmt_tokens.len() - 1].concat();
+    let message_type = bracketed.trim_matches(|c| c == '<' || c == '>').trim();
+
+    vec![to_snake_case(ident), message_type.to_string()]
+}
+
+/// `const type ident = value` - a named constant rather than a field; recorded as
+/// `[ident, rust_type, value]`. `main` leaves these out of the class graph, so const members do
+/// not appear in the generated code.
+fn parse_const_stmt(stmt_tokens: &[&str]) -> Vec<String> {
+    let rust_type = match_type(stmt_tokens[1]).to_string();
+    let ident = to_snake_case(stmt_tokens[2]);
+    let value = stmt_tokens[stmt_tokens.len() - 1].to_string();
+
+    vec![ident, rust_type, value]
+}
+
+/// Returns Vector of [EnumMember], with each RHS expression evaluated into an `i64` and
+/// resolvable against previously-defined members of the same enum.
+fn parse_enum_members(stmt_vector: Vec<&[u8]>) -> Vec<EnumMember> {
+    let mut symbols: HashMap<String, i64> = HashMap::new();
+    let mut members = Vec::with_capacity(stmt_vector.len());
+
+    for raw_stmt in stmt_vector {
+        let stmt = std::str::from_utf8(raw_stmt).unwrap().trim();
+        let mut sides = stmt.splitn(2, '=');
+        let name = sides.next().unwrap().trim().to_string();
+        let rhs = sides.next().unwrap().trim();
+
+        let (expr, obsolete) = strip_obsolete_suffix(rhs);
+        let rhs_tokens: Vec<&str> = expr.split_whitespace().collect();
+
+        let value = eval_enum_expr(&rhs_tokens, &symbols);
+        symbols.insert(name.clone(), value);
+        members.push(EnumMember { name, value, obsolete });
+    }
+
+    members
+}
+
+/// Strips a trailing `obsolete` marker off an enum member's RHS, along with the optional quoted
+/// reason steamd allows after it (e.g. `4 obsolete "replaced by Foo"`), and reports whether one
+/// was found. Done at the string level, before whitespace-tokenizing the expression, since a
+/// quoted reason may itself contain spaces.
+fn strip_obsolete_suffix(rhs: &str) -> (&str, bool) {
+    let rhs = rhs.trim_end();
+
+    match rhs.find(OBSOLETE) {
+        Some(marker_start) => {
+            let before = &rhs[..marker_start];
+            let after = rhs[marker_start + OBSOLETE.len()..].trim();
+
+            let is_word_boundary =
+                before.chars().next_back().map_or(true, |c| !c.is_alphanumeric());
+            let after_is_reason_or_empty =
+                after.is_empty() || (after.starts_with('"') && after.ends_with('"'));
+
+            if is_word_boundary && after_is_reason_or_empty {
+                (before.trim_end(), true)
+            } else {
+                (rhs, false)
+            }
+        }
+        None => (rhs, false),
+    }
+}
+
+/// Evaluates the right-hand side of an enum member assignment strictly left-to-right, since
+/// steamd expressions never need operator precedence. Supports decimal/hex literals, `<<`, `|`
+/// and references to previously-defined members of the same enum.
+fn eval_enum_expr(tokens: &[&str], symbols: &HashMap<String, i64>) -> i64 {
+    let mut result = eval_enum_operand(tokens[0], symbols);
+
+    let mut index = 1;
+    while index < tokens.len() {
+        let operator = tokens[index];
+        let operand_token = *tokens.get(index + 1).unwrap_or_else(|| {
+            panic!("enum expression ends with a dangling operator: {}", operator)
+        });
+        let operand = eval_enum_operand(operand_token, symbols);
+        result = match operator {
+            "<<" => result << operand,
+            "|" => result | operand,
+            _ => panic!("unsupported operator in enum expression: {}", operator),
+        };
+        index += 2;
+    }
+
+    result
+}
+
+/// Resolves a single token of an enum RHS expression: a decimal literal, a `0x` hex literal, or
+/// an identifier referring to an earlier member of the same enum.
+fn eval_enum_operand(token: &str, symbols: &HashMap<String, i64>) -> i64 {
+    if let Some(hex) = token.strip_prefix("0x").or_else(|| token.strip_prefix("0X")) {
+        i64::from_str_radix(hex, 16).unwrap()
+    } else if let Ok(decimal) = token.parse::<i64>() {
+        decimal
+    } else {
+        *symbols
+            .get(token)
+            .unwrap_or_else(|| panic!("reference to undefined enum member: {}", token))
+    }
+}
+
+/// Renders a parsed enum block into ready-to-emit Rust source: a plain `enum` with the
+/// configured derives (plus a `FromStr` impl backed by `FromPrimitive`, when the config enables
+/// it and derives `FromPrimitive`) for regular enums, or a
+/// `bitflags!` block for `flags` enums so OR-combined discriminants round-trip.
+fn render_enum_item(block: &EnumBlock, members: &[EnumMember], config: &DeriveConfig) -> String {
+    if block.is_flags {
+        render_flags_enum(&block.name, members)
+    } else {
+        render_plain_enum(&block.name, members, config)
+    }
+}
+
+fn render_plain_enum(name: &str, members: &[EnumMember], config: &DeriveConfig) -> String {
+    let mut code = format!("{}\npub enum {} {{\n", config.enum_attribute(), name);
+    for member in members {
+        code.push_str(&render_member_line(member, "", "    ", ','));
+    }
+    code.push_str("}\n");
+
+    // The impl below calls `num_traits::FromPrimitive::from_u32`, which only exists if the enum
+    // actually derives it - skip the impl rather than emit code that won't compile.
+    if config.emit_enum_from_primitive && config.enum_derives.contains(&"FromPrimitive") {
+        code.push_str(&render_from_primitive_impl(name));
+    }
+
+    code
+}
+
+/// Renders one `Ident = value` member line shared by plain enums and `bitflags!` blocks, with
+/// `#[deprecated]` attached above obsolete members either way.
+fn render_member_line(member: &EnumMember, prefix: &str, indent: &str, terminator: char) -> String {
+    let mut line = String::new();
+    if member.obsolete {
+        line.push_str(&format!("{}#[deprecated]\n", indent));
+    }
+    line.push_str(&format!(
+        "{}{}{} = {}{}\n",
+        indent, prefix, member.name, member.value, terminator
+    ));
+    line
+}
+
+/// Fully-qualified paths throughout so the snippet compiles wherever it's spliced without relying
+/// on a `use std::str::FromStr`/`use num_traits::FromPrimitive` the consumer may not have emitted.
+fn render_from_primitive_impl(name: &str) -> String {
+    format!(
+        "\nimpl std::str::FromStr for {name} {{\n    type Err = ();\n\n    fn from_str(s: &str) \
+         -> Result<Self, Self::Err> {{\n        let number = s.parse::<u32>().unwrap();\n        \
+         Ok(<{name} as num_traits::FromPrimitive>::from_u32(number).unwrap())\n    }}\n}}\n",
+        name = name
+    )
+}
+
+/// `bitflags::bitflags!` is invoked via its full path so the snippet doesn't depend on the
+/// consumer having a `use bitflags::bitflags;` in scope.
+fn render_flags_enum(name: &str, members: &[EnumMember]) -> String {
+    let mut code = format!("bitflags::bitflags! {{\n    pub struct {}: i64 {{\n", name);
+    for member in members {
+        code.push_str(&render_member_line(member, "const ", "        ", ';'));
+    }
+    code.push_str("    }\n}\n");
+    code
+}
+
+/// Extracts size from byte<%> where % is an integer
+fn array_extract_size(slice: &str) -> String {
+    slice.to_string().replacen(|c| !char::is_numeric(c), "", 10)
+}
+
+/// Checks if type is array - only possible type is byte array
+fn is_array(string: &str) -> bool {
+    string.find(|c: char| (c == '<') || (c == '>')).is_some()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::{
+        array_extract_size, eval_enum_expr, extract_enum_code, extract_members_exhaustive,
+        is_array, parse_enum_members, parse_stmts, render_enum_item, split_words_to_vec,
+        DeriveConfig,
+    };
+
+    fn gen_stmt_unknown_type() -> &'static str {
+        "steamidmarshal ulong steamId"
+    }
+
+    fn gen_stmt_known_type() -> &'static str {
+        "ulong steamId"
+    }
+
+    fn gen_members_code() -> &'static str {
+        "\r\n\tulong giftId;\r\n\tbyte giftType;\r\n\tuint accountId;\r\n"
+    }
+
+    fn gen_members_vec() -> Vec<String> {
+        vec!["ulong giftId".into(), "byte<10> giftType".into(), "uint accountId".into()]
+    }
+
+    #[test]
+    fn test_split_tokens() {
+        let stmt = gen_stmt_known_type();
+        let wat = split_words_to_vec(stmt);
+        assert_eq!(vec!["ulong", "steamId"], wat);
+    }
+
+    #[test]
+    fn test_extract_members_exhaustive() {
+        let code = gen_members_code();
+        let members = extract_members_exhaustive(code.as_ref());
+        let stringify: Vec<String> =
+            members.iter().map(|c| String::from_utf8(c.to_vec()).unwrap()).collect();
+        assert_eq!(vec!["ulong giftId", "byte giftType", "uint accountId"], stringify)
+    }
+
+    #[test]
+    fn test_parse_unknown_type() {
+        let parsed = parse_stmts(vec![gen_stmt_unknown_type().into()]);
+        assert_eq!(vec![vec!["steam_id".to_string(), "SteamID".to_string()]], parsed);
+    }
+
+    #[test]
+    fn test_parse_gameidmarshal() {
+        let parsed = parse_stmts(vec!["gameidmarshal ulong gameId".into()]);
+        assert_eq!(vec![vec!["game_id".to_string(), "GameID".to_string()]], parsed);
+    }
+
+    #[test]
+    fn test_parse_boolmarshal() {
+        let parsed = parse_stmts(vec!["boolmarshal byte isSteam".into()]);
+        assert_eq!(vec![vec!["is_steam".to_string(), "bool".to_string()]], parsed);
+    }
+
+    #[test]
+    fn test_parse_proto() {
+        let parsed = parse_stmts(vec!["proto < CMsgClientHello > message".into()]);
+        assert_eq!(vec![vec!["message".to_string(), "CMsgClientHello".to_string()]], parsed);
+    }
+
+    #[test]
+    fn test_parse_proto_tight_brackets() {
+        let parsed = parse_stmts(vec!["proto <CMsgClientHello> message".into()]);
+        assert_eq!(vec![vec!["message".to_string(), "CMsgClientHello".to_string()]], parsed);
+    }
+
+    #[test]
+    fn test_parse_const() {
+        let parsed = parse_stmts(vec!["const int ProtocolVersion = 65580".into()]);
+        let expected =
+            vec!["protocol_version".to_string(), "i32".to_string(), "65580".to_string()];
+        assert_eq!(vec![expected], parsed);
+    }
+
+    #[test]
+    fn test_parse_known_types() {
+        let non_parsed_vec = gen_members_vec();
+        let parsed_vec = parse_stmts(non_parsed_vec);
+        let test_vec = [["gift_id", "u64"], ["gift_type", "[u8; 10]"], ["account_id", "u32"]];
+
+        for vec in test_vec.iter().zip(parsed_vec.iter()) {
+            let x: Vec<&str> = vec.1.iter().map(|c| c.as_str()).collect();
+            assert_eq!(vec.0.to_vec(), x)
+        }
+    }
+
+    #[test]
+    fn test_array() {
+        let array = "byte<10>";
+        let not_array = "byte";
+
+        assert_eq!(true, is_array(array));
+        assert_eq!(false, is_array(not_array));
+        assert_eq!(10, array_extract_size(array).parse::<u32>().unwrap());
+    }
+
+    fn gen_enum_code() -> &'static str {
+        "enum EConfirmationType\r\n{\r\n\tUnknown = 0;\r\n\tGeneric = 1;\r\n\tTrade = Generic;\r\n};"
+    }
+
+    fn gen_enum_flags_code() -> &'static str {
+        "enum EClientPersonaStateFlag flags\r\n{\r\n\tStatus = 1;\r\n\tPlayerName = 1 << 1;\r\n\t\
+         Both = Status | PlayerName;\r\n};"
+    }
+
+    #[test]
+    fn test_extract_enum_code() {
+        let block = extract_enum_code(gen_enum_code().as_ref()).unwrap();
+        assert_eq!("EConfirmationType", block.name);
+        assert_eq!(false, block.is_flags);
+    }
+
+    #[test]
+    fn test_extract_enum_flags_code() {
+        let block = extract_enum_code(gen_enum_flags_code().as_ref()).unwrap();
+        assert_eq!("EClientPersonaStateFlag", block.name);
+        assert_eq!(true, block.is_flags);
+    }
+
+    #[test]
+    fn test_parse_enum_members() {
+        let block = extract_enum_code(gen_enum_code().as_ref()).unwrap();
+        let members = extract_members_exhaustive(block.body);
+        let parsed = parse_enum_members(members);
+
+        assert_eq!(3, parsed.len());
+        assert_eq!(("Unknown", 0), (parsed[0].name.as_str(), parsed[0].value));
+        assert_eq!(("Generic", 1), (parsed[1].name.as_str(), parsed[1].value));
+        assert_eq!(("Trade", 1), (parsed[2].name.as_str(), parsed[2].value));
+    }
+
+    #[test]
+    fn test_parse_enum_members_flags() {
+        let block = extract_enum_code(gen_enum_flags_code().as_ref()).unwrap();
+        let members = extract_members_exhaustive(block.body);
+        let parsed = parse_enum_members(members);
+
+        assert_eq!(("Status", 1), (parsed[0].name.as_str(), parsed[0].value));
+        assert_eq!(("PlayerName", 2), (parsed[1].name.as_str(), parsed[1].value));
+        assert_eq!(("Both", 3), (parsed[2].name.as_str(), parsed[2].value));
+    }
+
+    #[test]
+    fn test_parse_enum_members_obsolete() {
+        let members: Vec<&[u8]> = vec!["Old = 4 obsolete".as_ref(), "New = 5".as_ref()];
+        let parsed = parse_enum_members(members);
+
+        assert_eq!(true, parsed[0].obsolete);
+        assert_eq!(false, parsed[1].obsolete);
+        assert_eq!(5, parsed[1].value);
+    }
+
+    #[test]
+    fn test_parse_enum_members_obsolete_with_reason() {
+        let members: Vec<&[u8]> = vec!["Old = 4 obsolete \"replaced by New\"".as_ref()];
+        let parsed = parse_enum_members(members);
+
+        assert_eq!(true, parsed[0].obsolete);
+        assert_eq!(4, parsed[0].value);
+    }
+
+    #[test]
+    #[should_panic(expected = "dangling operator")]
+    fn test_eval_enum_expr_dangling_operator_panics_cleanly() {
+        eval_enum_expr(&["1", "<<"], &HashMap::new());
+    }
+
+    #[test]
+    fn test_parse_enum_members_obsolete_reason_mentioning_obsolete() {
+        let members: Vec<&[u8]> =
+            vec!["Old = 4 obsolete \"no longer used, obsolete behavior\"".as_ref()];
+        let parsed = parse_enum_members(members);
+
+        assert_eq!(true, parsed[0].obsolete);
+        assert_eq!(4, parsed[0].value);
+    }
+
+    #[test]
+    fn test_parse_enum_members_hex() {
+        let members: Vec<&[u8]> = vec!["Flag = 0x10".as_ref()];
+        let parsed = parse_enum_members(members);
+
+        assert_eq!(16, parsed[0].value);
+    }
+
+    #[test]
+    fn test_class_derive_attribute() {
+        let config = DeriveConfig::default();
+        assert_eq!("#[derive(Debug, Clone, PartialEq)]", config.class_attribute());
+    }
+
+    #[test]
+    fn test_enum_derive_attribute() {
+        let config = DeriveConfig::default();
+        assert_eq!(
+            "#[derive(Debug, Copy, Clone, Serialize, Deserialize, Eq, PartialEq, FromPrimitive)]",
+            config.enum_attribute()
+        );
+    }
+
+    #[test]
+    fn test_render_plain_enum() {
+        let block = extract_enum_code(gen_enum_code().as_ref()).unwrap();
+        let members = extract_members_exhaustive(block.body);
+        let parsed = parse_enum_members(members);
+        let config = DeriveConfig::default();
+
+        let rendered = render_enum_item(&block, &parsed, &config);
+
+        assert!(rendered.starts_with(&config.enum_attribute()));
+        assert!(rendered.contains("pub enum EConfirmationType {"));
+        assert!(rendered.contains("    Unknown = 0,\n"));
+        assert!(rendered.contains("    Generic = 1,\n"));
+        assert!(rendered.contains("    Trade = 1,\n"));
+        assert!(rendered.contains("impl std::str::FromStr for EConfirmationType {"));
+    }
+
+    #[test]
+    fn test_render_flags_enum() {
+        let block = extract_enum_code(gen_enum_flags_code().as_ref()).unwrap();
+        let members = extract_members_exhaustive(block.body);
+        let parsed = parse_enum_members(members);
+        let config = DeriveConfig::default();
+
+        let rendered = render_enum_item(&block, &parsed, &config);
+
+        assert!(rendered.starts_with("bitflags::bitflags! {"));
+        assert!(rendered.contains("pub struct EClientPersonaStateFlag: i64 {"));
+        assert!(rendered.contains("        const Status = 1;\n"));
+        assert!(rendered.contains("        const PlayerName = 2;\n"));
+        assert!(rendered.contains("        const Both = 3;\n"));
+        assert!(!rendered.contains("#[derive"));
+    }
+
+    #[test]
+    fn test_render_enum_obsolete_member() {
+        let block = extract_enum_code(gen_enum_code().as_ref()).unwrap();
+        let members: Vec<super::EnumMember> =
+            vec![super::EnumMember { name: "Old".to_string(), value: 4, obsolete: true }];
+        let config = DeriveConfig::default();
+
+        let rendered = render_enum_item(&block, &members, &config);
+
+        assert!(rendered.contains("#[deprecated]\n    Old = 4,\n"));
+        assert!(!rendered.contains("(obsolete)"));
+    }
+}
@@ -0,0 +1,154 @@
+use petgraph::graph::NodeIndex;
+use petgraph::visit::EdgeRef;
+use petgraph::Graph;
+
+/// Path the generated source is written to, relative to this crate's manifest.
+const OUTPUT_FILE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/src/messages.rs");
+
+/// Walks the graph built by `bin/generate` and writes the rendered Rust source it describes to
+/// [`OUTPUT_FILE`].
+///
+/// `entry`'s direct children are `class` or `enum`/`enum_flags` items; see [`render_tree`] for
+/// how each kind is rendered.
+pub fn generate_file_from_tree(graph: Graph<String, &str>, entry: NodeIndex) {
+    let rendered = render_tree(&graph, entry);
+    std::fs::write(OUTPUT_FILE, rendered)
+        .unwrap_or_else(|err| panic!("failed to write generated file {}: {}", OUTPUT_FILE, err));
+}
+
+/// Pure rendering step, split out from [`generate_file_from_tree`] so it can be exercised without
+/// touching the filesystem.
+///
+/// - A `class` child renders as a `pub struct`: its `derive` child is the `#[derive(...)]` line
+///   (an attribute on the struct, not a field), and its `0` children are flat `(name, type)` pairs
+///   for the struct's fields. `const` members (which parse to three flat tokens rather than two)
+///   aren't represented by this node shape and are skipped.
+/// - An `enum`/`enum_flags` child renders as-is: its `code` child already holds the fully rendered
+///   `enum`/`bitflags!` block produced by `render_enum_item` in `bin/generate`.
+fn render_tree(graph: &Graph<String, &str>, entry: NodeIndex) -> String {
+    let mut output = String::new();
+
+    for edge in ordered_edges(graph, entry) {
+        let item_node = edge.target();
+
+        match *edge.weight() {
+            "class" => output.push_str(&render_class(graph, item_node)),
+            "enum" | "enum_flags" => output.push_str(&render_enum(graph, item_node)),
+            _ => {}
+        }
+    }
+
+    output
+}
+
+fn render_class(graph: &Graph<String, &str>, class_node: NodeIndex) -> String {
+    let name = &graph[class_node];
+
+    let mut derive = String::new();
+    let mut fields = Vec::new();
+    for edge in ordered_edges(graph, class_node) {
+        match *edge.weight() {
+            "derive" => derive = graph[edge.target()].clone(),
+            "0" => fields.push(&graph[edge.target()]),
+            _ => {}
+        }
+    }
+
+    let mut code = format!("{}\npub struct {} {{\n", derive, name);
+    for pair in fields.chunks(2) {
+        if let [field_name, field_type] = pair {
+            code.push_str(&format!("    pub {}: {},\n", field_name, field_type));
+        }
+    }
+    code.push_str("}\n\n");
+    code
+}
+
+fn render_enum(graph: &Graph<String, &str>, enum_node: NodeIndex) -> String {
+    ordered_edges(graph, enum_node)
+        .into_iter()
+        .find(|edge| *edge.weight() == "code")
+        .map(|edge| format!("{}\n", graph[edge.target()]))
+        .unwrap_or_default()
+}
+
+/// `Graph::edges` walks a node's adjacency list, which petgraph builds by prepending on each
+/// insertion - so edges come back in reverse insertion order. Collecting and reversing here
+/// restores the order `bin/generate` added them in.
+fn ordered_edges<'a>(
+    graph: &'a Graph<String, &str>,
+    node: NodeIndex,
+) -> Vec<petgraph::graph::EdgeReference<'a, &'a str>> {
+    let mut edges: Vec<_> = graph.edges(node).collect();
+    edges.reverse();
+    edges
+}
+
+#[cfg(test)]
+mod tests {
+    use petgraph::Graph;
+
+    use super::render_tree;
+
+    #[test]
+    fn test_render_class() {
+        let mut graph = Graph::<String, &str>::new();
+        let entry = graph.add_node("entry".to_string());
+
+        let class_node = graph.add_node("CMsgFoo".to_string());
+        graph.add_edge(entry, class_node, "class");
+
+        let derive_node = graph.add_node("#[derive(Debug, Clone, PartialEq)]".to_string());
+        graph.add_edge(class_node, derive_node, "derive");
+
+        let name_node = graph.add_node("giftId".to_string());
+        graph.add_edge(class_node, name_node, "0");
+        let type_node = graph.add_node("u64".to_string());
+        graph.add_edge(class_node, type_node, "0");
+
+        let rendered = render_tree(&graph, entry);
+
+        assert!(rendered.starts_with("#[derive(Debug, Clone, PartialEq)]\npub struct CMsgFoo {"));
+        assert!(rendered.contains("    pub giftId: u64,\n"));
+    }
+
+    #[test]
+    fn test_render_enum_appears_in_output() {
+        let mut graph = Graph::<String, &str>::new();
+        let entry = graph.add_node("entry".to_string());
+
+        let enum_node = graph.add_node("EConfirmationType".to_string());
+        graph.add_edge(entry, enum_node, "enum");
+
+        let code = "#[derive(Debug)]\npub enum EConfirmationType {\n    Unknown = 0,\n}\n";
+        let code_node = graph.add_node(code.to_string());
+        graph.add_edge(enum_node, code_node, "code");
+
+        let rendered = render_tree(&graph, entry);
+
+        assert!(rendered.contains("pub enum EConfirmationType {"));
+        assert!(rendered.contains("    Unknown = 0,\n"));
+    }
+
+    #[test]
+    fn test_class_and_enum_preserve_insertion_order() {
+        let mut graph = Graph::<String, &str>::new();
+        let entry = graph.add_node("entry".to_string());
+
+        let class_node = graph.add_node("CMsgFoo".to_string());
+        graph.add_edge(entry, class_node, "class");
+        let derive_node = graph.add_node("#[derive(Debug)]".to_string());
+        graph.add_edge(class_node, derive_node, "derive");
+
+        let enum_node = graph.add_node("EBar".to_string());
+        graph.add_edge(entry, enum_node, "enum");
+        let code_node = graph.add_node("pub enum EBar {}\n".to_string());
+        graph.add_edge(enum_node, code_node, "code");
+
+        let rendered = render_tree(&graph, entry);
+
+        let class_pos = rendered.find("CMsgFoo").unwrap();
+        let enum_pos = rendered.find("EBar").unwrap();
+        assert!(class_pos < enum_pos);
+    }
+}
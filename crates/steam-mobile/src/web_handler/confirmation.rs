@@ -19,10 +19,56 @@ pub struct Confirmation {
 
 /// We retrieve [ConfirmationDetails] as a json object.
 /// There is also the need to already have a [Confirmation].
+///
+/// Which variant is populated depends on the [Confirmation]'s [EConfirmationType]; confirmation
+/// kinds we don't carry an extra identifier for yet fall back to [ConfirmationDetails::Other].
 #[derive(Debug, Clone, PartialEq, Copy)]
-pub struct ConfirmationDetails {
-    /// ID of the trade offer. Has a value if EConfirmationType::Trade
-    pub trade_offer_id: Option<i64>,
+pub enum ConfirmationDetails {
+    /// Details of a [EConfirmationType::Trade] confirmation
+    Trade { trade_offer_id: i64 },
+    /// Details of a [EConfirmationType::Market] confirmation
+    Market { market_listing_id: i64 },
+    /// Details of any other confirmation kind
+    Other,
+}
+
+impl ConfirmationDetails {
+    /// Returns the trade offer id, if this is the details of a [EConfirmationType::Trade]
+    /// confirmation.
+    pub fn trade_offer_id(&self) -> Option<i64> {
+        match *self {
+            ConfirmationDetails::Trade { trade_offer_id } => Some(trade_offer_id),
+            _ => None,
+        }
+    }
+
+    /// Returns the market listing id, if this is the details of a [EConfirmationType::Market]
+    /// confirmation.
+    pub fn market_listing_id(&self) -> Option<i64> {
+        match *self {
+            ConfirmationDetails::Market { market_listing_id } => Some(market_listing_id),
+            _ => None,
+        }
+    }
+
+    /// Builds the variant matching `kind` out of the scraped `creator_id` - the trade offer id
+    /// for a [EConfirmationType::Trade] confirmation, the market listing id for a
+    /// [EConfirmationType::Market] one. Anything else (including a `creator_id` that failed to
+    /// parse) falls back to [ConfirmationDetails::Other].
+    ///
+    /// This is the supported way to build a [ConfirmationDetails] going forward, replacing the
+    /// old `ConfirmationDetails { trade_offer_id: Some(..) }` struct literal.
+    pub fn from_kind(kind: EConfirmationType, creator_id: Option<i64>) -> Self {
+        match (kind, creator_id) {
+            (EConfirmationType::Trade, Some(trade_offer_id)) => {
+                ConfirmationDetails::Trade { trade_offer_id }
+            }
+            (EConfirmationType::Market, Some(market_listing_id)) => {
+                ConfirmationDetails::Market { market_listing_id }
+            }
+            _ => ConfirmationDetails::Other,
+        }
+    }
 }
 
 #[derive(Debug, Copy, Clone, Serialize, Deserialize, Eq, PartialEq, FromPrimitive)]
@@ -53,6 +99,46 @@ impl FromStr for EConfirmationType {
     }
 }
 
+/// Shape of a single entry in Steam's `getlist` mobile-confirmation-queue response. `creator_id`
+/// is the trade offer id for a `Trade` confirmation and the market listing id for a `Market` one
+/// - which one depends on `kind`, so it's kept as a string here and resolved in [Confirmation]'s
+/// [From] impl rather than typed as an `i64` up front.
+#[derive(Debug, Clone, Deserialize)]
+struct RawConfirmation {
+    id: String,
+    nonce: String,
+    #[serde(rename = "type")]
+    kind: u32,
+    creator_id: String,
+}
+
+impl From<RawConfirmation> for Confirmation {
+    fn from(raw: RawConfirmation) -> Self {
+        let kind = EConfirmationType::from_u32(raw.kind).unwrap_or(EConfirmationType::Unknown);
+        let creator_id = raw.creator_id.parse::<i64>().ok();
+
+        Confirmation {
+            id: raw.id,
+            key: raw.nonce,
+            kind,
+            details: Some(ConfirmationDetails::from_kind(kind, creator_id)),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct ConfirmationListResponse {
+    conf: Vec<RawConfirmation>,
+}
+
+/// Parses Steam's `getlist` mobile-confirmation JSON response into [Confirmations], populating
+/// each [Confirmation]'s [ConfirmationDetails] from its `creator_id` via
+/// [ConfirmationDetails::from_kind].
+pub fn parse_confirmations(json: &str) -> serde_json::Result<Confirmations> {
+    let response: ConfirmationListResponse = serde_json::from_str(json)?;
+    Ok(Confirmations::from(response.conf.into_iter().map(Confirmation::from).collect::<Vec<_>>()))
+}
+
 impl Confirmations {
     /// This is a convenience function that lets you handle confirmations based if is a trade or
     /// market confirmation.
@@ -110,20 +196,55 @@ impl Confirmations {
     where
         T: AsRef<[i64]>,
     {
-        self.0.retain(|c| {
-            if let Some(conf_details) = c.details {
-                let trade_offer_id = conf_details.trade_offer_id.unwrap();
-                return trade_offer_ids.as_ref().iter().any(|&id| id == trade_offer_id);
-            }
-            false
+        self.retain_by(|c| {
+            c.details
+                .and_then(|details| details.trade_offer_id())
+                .map_or(false, |id| trade_offer_ids.as_ref().contains(&id))
+        });
+    }
+
+    /// Filter market listing ids in-place.
+    ///
+    /// This is a convenience function that lets you handle confirmations based on market
+    /// listing ids, the same way [Confirmations::filter_by_trade_offer_ids] does for trade
+    /// offers.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use steam_mobile::Confirmations;
+    /// # let mut confirmations = Confirmations::default();
+    /// let market_listing_ids = vec![3978341051i64];
+    /// confirmations.filter_by_market_listing_ids(&market_listing_ids);
+    /// ```
+    pub fn filter_by_market_listing_ids<T>(&mut self, market_listing_ids: T)
+    where
+        T: AsRef<[i64]>,
+    {
+        self.retain_by(|c| {
+            c.details
+                .and_then(|details| details.market_listing_id())
+                .map_or(false, |id| market_listing_ids.as_ref().contains(&id))
         });
     }
 
+    /// Retains only the confirmations for which `predicate` returns `true`, discarding the rest.
+    ///
+    /// This is the generic building block behind [Confirmations::filter_by_trade_offer_ids] and
+    /// [Confirmations::filter_by_market_listing_ids], for callers that need to filter on
+    /// something else entirely.
+    pub fn retain_by<F>(&mut self, predicate: F)
+    where
+        F: Fn(&Confirmation) -> bool,
+    {
+        self.0.retain(predicate);
+    }
+
     pub fn has_trade_offer_id(&self, trade_offer_id: i64) -> bool {
         self.0.iter().any(|conf| {
             conf.details
                 .as_ref()
-                .map_or(false, |details| details.trade_offer_id == Some(trade_offer_id))
+                .and_then(|details| details.trade_offer_id())
+                .map_or(false, |id| id == trade_offer_id)
         })
     }
 }
@@ -170,31 +291,25 @@ mod tests {
             id: "7676451136".to_string(),
             key: "18064583892738866189".to_string(),
             kind: EConfirmationType::Trade,
-            details: Some(ConfirmationDetails {
-                trade_offer_id: Some(4009687284),
-            }),
+            details: Some(ConfirmationDetails::Trade { trade_offer_id: 4009687284 }),
         });
         vec.push(Confirmation {
             id: "7652515663".to_string(),
             key: "10704556181383316145".to_string(),
             kind: EConfirmationType::Trade,
-            details: Some(ConfirmationDetails {
-                trade_offer_id: Some(4000980011),
-            }),
+            details: Some(ConfirmationDetails::Trade { trade_offer_id: 4000980011 }),
         });
         vec.push(Confirmation {
             id: "7652555421".to_string(),
             key: "10704556181383323456".to_string(),
             kind: EConfirmationType::Trade,
-            details: Some(ConfirmationDetails {
-                trade_offer_id: Some(4000793103),
-            }),
+            details: Some(ConfirmationDetails::Trade { trade_offer_id: 4000793103 }),
         });
         vec.push(Confirmation {
             id: "7652515663".to_string(),
             key: "20845677815483316145".to_string(),
             kind: EConfirmationType::Market,
-            details: None,
+            details: Some(ConfirmationDetails::Market { market_listing_id: 3978341051 }),
         });
         Confirmations::from(vec)
     }
@@ -222,16 +337,85 @@ mod tests {
         let third = 33311221; // no existant
         let tradeoffer_id = vec![first, second, third];
 
-        let details_0 = ConfirmationDetails {
-            trade_offer_id: Some(first),
-        };
-        let details_1 = ConfirmationDetails {
-            trade_offer_id: Some(second),
-        };
+        let details_0 = ConfirmationDetails::Trade { trade_offer_id: first };
+        let details_1 = ConfirmationDetails::Trade { trade_offer_id: second };
 
         confirmations.filter_by_trade_offer_ids(tradeoffer_id);
         assert_eq!(confirmations.0.get(0).unwrap().details, Some(details_0));
         assert_eq!(confirmations.0.get(1).unwrap().details, Some(details_1));
         assert_eq!(confirmations.0.get(2), None);
     }
+
+    #[test]
+    fn filter_market_listing_id() {
+        let mut confirmations = get_confirmations();
+        let market_listing_ids = vec![3978341051i64];
+
+        confirmations.filter_by_market_listing_ids(&market_listing_ids);
+
+        assert_eq!(confirmations.0.len(), 1);
+        assert_eq!(confirmations.0[0].kind, EConfirmationType::Market);
+    }
+
+    #[test]
+    fn retain_by_predicate() {
+        let mut confirmations = get_confirmations();
+        confirmations.retain_by(|c| c.kind == EConfirmationType::Trade);
+
+        assert_eq!(confirmations.0.len(), 3);
+        assert!(confirmations.0.iter().all(|c| c.kind == EConfirmationType::Trade));
+    }
+
+    #[test]
+    fn parse_confirmations_from_real_json() {
+        let json = r#"{
+            "success": true,
+            "conf": [
+                {
+                    "id": "7676451136",
+                    "type": 2,
+                    "type_name": "Trade Offer",
+                    "creator_id": "4009687284",
+                    "nonce": "18064583892738866189",
+                    "creation_time": 1610000000,
+                    "cancel": "Cancel",
+                    "accept": "Accept",
+                    "icon": "",
+                    "multi": false,
+                    "headline": "Trade Offer #4009687284",
+                    "summary": ["Give: Item A", "Receive: Item B"],
+                    "warn": []
+                },
+                {
+                    "id": "7652515663",
+                    "type": 3,
+                    "type_name": "Sell Listing",
+                    "creator_id": "3978341051",
+                    "nonce": "20845677815483316145",
+                    "creation_time": 1610000500,
+                    "cancel": "Cancel",
+                    "accept": "Accept",
+                    "icon": "",
+                    "multi": false,
+                    "headline": "Listing Created",
+                    "summary": ["Sell Item C"],
+                    "warn": []
+                }
+            ]
+        }"#;
+
+        let confirmations = parse_confirmations(json).unwrap();
+
+        assert_eq!(confirmations.0.len(), 2);
+        assert_eq!(confirmations.0[0].kind, EConfirmationType::Trade);
+        assert_eq!(
+            confirmations.0[0].details,
+            Some(ConfirmationDetails::Trade { trade_offer_id: 4009687284 })
+        );
+        assert_eq!(confirmations.0[1].kind, EConfirmationType::Market);
+        assert_eq!(
+            confirmations.0[1].details,
+            Some(ConfirmationDetails::Market { market_listing_id: 3978341051 })
+        );
+    }
 }